@@ -0,0 +1,157 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+type FetchError = Box<dyn std::error::Error + Send + Sync>;
+/// Slot for each queued URL's result, filled in by whichever worker
+/// picks it up; `None` until that happens.
+type FetchResults = Arc<Mutex<Vec<Option<Result<String, FetchError>>>>>;
+
+const CACHE_DIR: &str = "./cache";
+const JOURNAL_PATH: &str = "./cache/journal.txt";
+const MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF_MS: u64 = 250;
+/// Minimum time between two requests to the same host, so a
+/// parallel fetch doesn't hammer raw.githubusercontent.com.
+const MIN_HOST_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Path the cache would write a fetched page to, keyed by a hash of
+/// its URL so re-runs can find it without re-downloading.
+fn cache_path(url: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    Path::new(CACHE_DIR).join(format!("{:x}.html", hasher.finish()))
+}
+
+/// URLs already marked complete in the journal, so a resumed run only
+/// fetches what's outstanding.
+fn load_journal() -> HashSet<String> {
+    fs::read_to_string(JOURNAL_PATH)
+        .map(|contents| contents.lines().map(|l| l.to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn mark_done(url: &str) -> std::io::Result<()> {
+    fs::create_dir_all(CACHE_DIR)?;
+    let mut journal = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(JOURNAL_PATH)?;
+    use std::io::Write;
+    writeln!(journal, "{}", url)
+}
+
+/// Host portion of a URL, used as the rate-limiting key.
+fn host_of(url: &str) -> String {
+    url.split("://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .unwrap_or(url)
+        .to_string()
+}
+
+/// Block until at least `MIN_HOST_INTERVAL` has passed since the last
+/// request to `url`'s host, then record this request's start time.
+fn throttle(url: &str, host_last: &Mutex<HashMap<String, Instant>>) {
+    let host = host_of(url);
+    let wait = {
+        let mut last = host_last.lock().unwrap();
+        let now = Instant::now();
+        // `prev` may lie in the future when another caller is already
+        // scheduled to wait, so measure from its scheduled start.
+        let wait = last
+            .get(&host)
+            .map(|prev| (*prev + MIN_HOST_INTERVAL).saturating_duration_since(now))
+            .filter(|wait| !wait.is_zero());
+        last.insert(host, now + wait.unwrap_or_default());
+        wait
+    };
+    if let Some(wait) = wait {
+        sleep(wait);
+    }
+}
+
+/// Fetch `url`, serving from the on-disk cache when a prior run already
+/// completed it, honoring per-host rate limiting and retrying
+/// transient failures with exponential backoff and jitter, up to
+/// `MAX_RETRIES` attempts.
+fn fetch_cached_throttled(
+    url: &str,
+    host_last: &Mutex<HashMap<String, Instant>>,
+) -> Result<String, FetchError> {
+    fs::create_dir_all(CACHE_DIR)?;
+    let path = cache_path(url);
+    if load_journal().contains(url) {
+        if let Ok(cached) = fs::read_to_string(&path) {
+            return Ok(cached);
+        }
+    }
+
+    let mut attempt = 0;
+    loop {
+        throttle(url, host_last);
+        match reqwest::blocking::get(url).and_then(|r| r.error_for_status()) {
+            Ok(response) => {
+                let text = response.text()?;
+                fs::write(&path, &text)?;
+                mark_done(url)?;
+                return Ok(text);
+            }
+            Err(err) if attempt < MAX_RETRIES => {
+                attempt += 1;
+                let jitter = rand::random::<u64>() % BASE_BACKOFF_MS;
+                let backoff = BASE_BACKOFF_MS * 2u64.pow(attempt - 1) + jitter;
+                eprintln!(
+                    "Fetch of {} failed ({}), retrying in {}ms (attempt {}/{})",
+                    url, err, backoff, attempt, MAX_RETRIES
+                );
+                sleep(Duration::from_millis(backoff));
+            }
+            Err(err) => return Err(Box::new(err)),
+        }
+    }
+}
+
+/// Fetch every URL in `urls` using a bounded pool of `concurrency`
+/// worker threads, applying the same per-host rate limit and
+/// cache/retry behavior as `fetch_cached_throttled`. Results are returned in the
+/// same order as `urls`, independent of which worker finished first,
+/// so callers can merge them deterministically.
+pub fn fetch_all_cached(urls: &[String], concurrency: usize) -> Vec<Result<String, FetchError>> {
+    let queue: Arc<Mutex<VecDeque<(usize, String)>>> =
+        Arc::new(Mutex::new(urls.iter().cloned().enumerate().collect()));
+    let host_last: Arc<Mutex<HashMap<String, Instant>>> = Arc::new(Mutex::new(HashMap::new()));
+    let results: FetchResults = Arc::new(Mutex::new((0..urls.len()).map(|_| None).collect()));
+
+    let worker_count = concurrency.max(1).min(urls.len().max(1));
+    let mut handles = Vec::with_capacity(worker_count);
+    for _ in 0..worker_count {
+        let queue = Arc::clone(&queue);
+        let host_last = Arc::clone(&host_last);
+        let results = Arc::clone(&results);
+        handles.push(std::thread::spawn(move || loop {
+            let next = queue.lock().unwrap().pop_front();
+            let Some((index, url)) = next else {
+                break;
+            };
+            let result = fetch_cached_throttled(&url, &host_last);
+            results.lock().unwrap()[index] = Some(result);
+        }));
+    }
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    Arc::try_unwrap(results)
+        .unwrap()
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every queued index is filled before workers exit"))
+        .collect()
+}
@@ -1,10 +1,23 @@
-use itertools::Itertools;
-use regex::Regex;
-use reqwest;
+mod fetch;
+mod manifest;
+mod pathas;
+mod patterns;
+mod transliterate;
+
+use fetch::fetch_all_cached;
+use manifest::{load_manifest, ScrapeTask};
+use pathas::{compare_krama, to_ghana, to_jata, to_krama};
+use patterns::{validate_captures, PatternRegistry};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
-use std::collections::HashMap;
+use transliterate::{transliterate, Scheme};
+
+/// Every verse is normalized to this scheme before being written out,
+/// so verses scraped from differently-encoded sources can be compared
+/// or merged directly.
+const CANONICAL_SCHEME: Scheme = Scheme::Iast;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Verse {
@@ -14,191 +27,211 @@ struct Verse {
     prasna: i32,
     panasa: i32,
     text: String,
+    scheme: Scheme,
 }
 
-fn extract_verses(text: &str, pattern: &Regex, verses: &mut HashMap<String, Verse>) {
-    for cap in pattern.captures_iter(text) {
-        let verse_index = cap.get(1).unwrap().as_str().to_string();
-        let index_parts: Vec<i32> = verse_index
-            .split('.')
-            .map(|x| x.parse::<i32>().unwrap())
-            .collect();
+fn extract_verses(
+    text: &str,
+    pattern: &regex::Regex,
+    index_arity: usize,
+    source_scheme: Scheme,
+    verses: &mut HashMap<String, Verse>,
+) {
+    let (accepted, report) = validate_captures(pattern, index_arity, text);
+    if report.rejected > 0 {
+        println!(
+            "  {} verses matched, {} rejected as malformed",
+            report.matched, report.rejected
+        );
+    }
 
-        let verse_text = cap.get(2).unwrap().as_str().trim().to_string();
+    for (index_parts, verse_text) in accepted {
+        // `validate_captures` only ever accepts an index with exactly
+        // `index_arity` parts, and `load_manifest` rejects any task
+        // whose `index_arity` isn't 4, so this holds for every task
+        // `Verse` is built from; it's asserted rather than assumed
+        // because `Verse` itself has no way to be built with anything
+        // other than 4 fields.
+        assert_eq!(
+            index_parts.len(),
+            4,
+            "Verse requires a 4-part bhaga.kanda.prasna.panasa index, got {:?}",
+            index_parts
+        );
+        let verse_index = index_parts
+            .iter()
+            .map(i32::to_string)
+            .collect::<Vec<_>>()
+            .join(".");
+        let verse = Verse {
+            index: verse_index.clone(),
+            bhaga: index_parts[0],
+            kanda: index_parts[1],
+            prasna: index_parts[2],
+            panasa: index_parts[3],
+            text: transliterate(&verse_text, source_scheme, CANONICAL_SCHEME),
+            scheme: CANONICAL_SCHEME,
+        };
+        verses.insert(verse_index, verse);
+    }
+}
 
-        if index_parts.len() == 4 {
-            let bhaga = index_parts[0];
-            let kanda = index_parts[1];
-            let prasna = index_parts[2];
-            let panasa = index_parts[3];
+/// Fetch every URL in `task`, extract verses with its registered
+/// pattern, write the merged result to `./outputs/{task.output}.json`,
+/// and return the same map so callers can cross-reference it against
+/// other tasks (e.g. deriving krama from padam).
+fn run_task(
+    task: &ScrapeTask,
+    patterns: &PatternRegistry,
+) -> Result<HashMap<String, Verse>, Box<dyn std::error::Error>> {
+    if !task.enabled {
+        println!("Skipping disabled task '{}'.", task.name);
+        return Ok(HashMap::new());
+    }
 
-            let verse = Verse {
-                index: verse_index.clone(),
-                bhaga,
-                kanda,
-                prasna,
-                panasa,
-                text: verse_text.clone(),
-            };
+    // Each URL may carry its own pattern override (e.g. samhita's
+    // kanda 01/03 source files), so patterns are resolved per URL
+    // rather than once for the whole task.
+    let expanded = task.expand_urls();
+    let urls: Vec<String> = expanded.iter().map(|(url, _)| url.clone()).collect();
+    let mut verses: HashMap<String, Verse> = HashMap::new();
 
-            verses.insert(verse_index.clone(), verse);
-        }
+    // Fetched concurrently, but merged in `expanded` order so the
+    // resulting map is the same regardless of which request finished
+    // first.
+    let fetched = fetch_all_cached(&urls, task.concurrency);
+    for ((url, pattern_str), result) in expanded.iter().zip(fetched) {
+        let text = result.map_err(|e| format!("failed to fetch {}: {}", url, e))?;
+        let pattern = patterns
+            .get(pattern_str)
+            .ok_or_else(|| format!("no compiled pattern for '{}'", pattern_str))?;
+        extract_verses(
+            &text,
+            pattern,
+            task.index_arity,
+            task.source_scheme,
+            &mut verses,
+        );
     }
+    println!("Fetched all the urls for '{}'.", task.name);
+
+    write_verses(&task.output, &verses)?;
+
+    Ok(verses)
 }
-fn scrape(
-    patterns_and_urls: Vec<(Regex, &str)>,
-    naming: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    
-    let mut verses: HashMap<String, Verse> = HashMap::new();
-    for pattern_and_url in patterns_and_urls.iter() {
-        let response = reqwest::blocking::get(pattern_and_url.1)?;
-        let text = response.text()?;
-        extract_verses(&text, &pattern_and_url.0, &mut verses);
-    }
-    println!("Fetched all the urls.");
-    let json_string = serde_json::to_string_pretty(&verses)?;
 
-    let file_name = format!("./outputs/{}.json", naming);
+/// Write `verses` as pretty JSON to `./outputs/{output}.json`.
+fn write_verses(
+    output: &str,
+    verses: &HashMap<String, Verse>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let json_string = serde_json::to_string_pretty(verses)?;
+    let file_name = format!("./outputs/{}.json", output);
     let mut file = File::create(file_name)?;
     file.write_all(json_string.as_bytes())?;
+    Ok(())
+}
 
+/// Derive a jaṭā/ghana verse at the same index as `source`, inheriting
+/// its bhaga/kanda/prasna/panasa and scheme.
+fn derived_verse(source: &Verse, text: String) -> Verse {
+    Verse {
+        index: source.index.clone(),
+        bhaga: source.bhaga,
+        kanda: source.kanda,
+        prasna: source.prasna,
+        panasa: source.panasa,
+        text,
+        scheme: source.scheme,
+    }
+}
+
+/// Derive jaṭā and ghana pāṭha from every padam verse and write them
+/// out the same way a fetched task's verses are, so they sit
+/// alongside samhita/padam/kramam under `./outputs/`.
+fn derive_pathas(padam: &HashMap<String, Verse>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut jata = HashMap::new();
+    let mut ghana = HashMap::new();
+    for (index, verse) in padam.iter() {
+        let words: Vec<String> = verse
+            .text
+            .split_whitespace()
+            .map(|w| w.to_string())
+            .collect();
+        jata.insert(
+            index.clone(),
+            derived_verse(verse, to_jata(&words).join(" ")),
+        );
+        ghana.insert(
+            index.clone(),
+            derived_verse(verse, to_ghana(&words).join(" ")),
+        );
+    }
+    write_verses("jata/TS", &jata)?;
+    write_verses("ghana/TS", &ghana)?;
+    println!(
+        "Derived jaṭā and ghana pāṭha for {} padam verses.",
+        padam.len()
+    );
     Ok(())
 }
 
+/// Derive krama from every padam verse and diff it against the
+/// scraped kramam verse at the same index, logging each index whose
+/// words differ and a summary of how many indices disagreed. Padam
+/// indices with no scraped kramam verse are skipped and not counted.
+fn cross_validate_krama(padam: &HashMap<String, Verse>, kramam: &HashMap<String, Verse>) {
+    let mut checked = 0;
+    let mut mismatched = 0;
+    for (index, padam_verse) in padam.iter() {
+        let Some(kramam_verse) = kramam.get(index) else {
+            continue;
+        };
+        checked += 1;
+        let words: Vec<String> = padam_verse
+            .text
+            .split_whitespace()
+            .map(|w| w.to_string())
+            .collect();
+        let generated = to_krama(&words);
+        let comparison = compare_krama(&generated, &kramam_verse.text);
+        if !comparison.mismatches.is_empty() {
+            mismatched += 1;
+            println!(
+                "krama mismatch at {}: {}/{} words differ",
+                index,
+                comparison.mismatches.len(),
+                comparison.total_words
+            );
+        }
+    }
+    println!(
+        "Cross-validated krama for {} indices ({} mismatched).",
+        checked, mismatched
+    );
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let samhita: Vec<(Regex, &str)> = vec![
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:TS \d+\.\d+\.\d+\.\d+|$)").unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/01/TS%201%20Baraha.brh",
-        ),
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:TS \d+\.\d+\.\d+\.\d+|$)").unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/03/TS%203%20Baraha.BRH",
-        ),
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:\nTS \d+\.\d+\.\d+\.\d+|$)")
-                .unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/02/TS%202%20Baraha.brh",
-        ),
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:\nTS \d+\.\d+\.\d+\.\d+|$)")
-                .unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/04/TS%204%20Baraha.BRH",
-        ),
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:\nTS \d+\.\d+\.\d+\.\d+|$)")
-                .unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/05/TS%205%20Baraha.BRH",
-        ),
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:\nTS \d+\.\d+\.\d+\.\d+|$)")
-                .unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/06/TS%206%20Baraha.BRH",
-        ),
-        (
-            Regex::new(r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:\nTS \d+\.\d+\.\d+\.\d+|$)")
-                .unwrap(),
-            "https://raw.githubusercontent.com/KYVeda/texts/master/saMhitA/07/TS%207%20Baraha.BRH",
-        ),
-    ];
-
-    let padam = vec![
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.1/TS%201.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.2/TS%201.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.3/TS%201.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.4/TS%201.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.5/TS%201.5%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.6/TS%201.6%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.7/TS%201.7%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-1.8/TS%201.8%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-2.1/TS%202.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-2.2/TS%202.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-2.3/TS%202.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-2.4/TS%202.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-2.5/TS%202.5%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-2.6/TS%202.6%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-3.1/TS%203.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-3.2/TS%203.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-3.3/TS%203.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-3.4/TS%203.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-3.5/TS%203.5%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.1/TS%204.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.2/TS%204.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.3/TS%204.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.4/TS%204.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.5/TS%204.5%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.6/TS%204.6%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-4.7/TS%204.7%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.1/TS%205.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.2/TS%205.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.3/TS%205.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.4/TS%205.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.5/TS%205.5%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.6/TS%205.6%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-5.7/TS%205.7%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-6.1/TS%206.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-6.2/TS%206.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-6.3/TS%206.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-6.4/TS%206.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-6.5/TS%206.5%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-6.6/TS%206.6%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-7.1/TS%207.1%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-7.2/TS%207.2%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-7.3/TS%207.3%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-7.4/TS%207.4%20Baraha%20Padam.BRH"),
-        (Regex::new(r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Padam/TS-7.5/TS%207.5%20Baraha%20Padam.BRH")
-    ];
-
-    let kramam = vec![
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.1/TS%201.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.2/TS%201.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.3/TS%201.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.4/TS%201.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.5/TS%201.5%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.6/TS%201.6%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.7/TS%201.7%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-1.8/TS%201.8%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-2.1/TS%202.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-2.2/TS%202.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-2.3/TS%202.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-2.4/TS%202.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-2.5/TS%202.5%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-2.6/TS%202.6%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-3.1/TS%203.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-3.2/TS%203.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-3.3/TS%203.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-3.4/TS%203.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-3.5/TS%203.5%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.1/TS%204.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.2/TS%204.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.3/TS%204.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.4/TS%204.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.5/TS%204.5%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.6/TS%204.6%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-4.7/TS%204.7%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.1/TS%205.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.2/TS%205.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.3/TS%205.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.4/TS%205.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.5/TS%205.5%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.6/TS%205.6%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-5.7/TS%205.7%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-6.1/TS%206.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-       (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-6.2/TS%206.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-6.3/TS%206.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-6.4/TS%206.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-6.5/TS%206.5%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-6.6/TS%206.6%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-7.1/TS%207.1%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-7.2/TS%207.2%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-7.3/TS%207.3%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-7.4/TS%207.4%20Krama%20Paaatm%20Sanskrit.BRH"),
-        (Regex::new(r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)").unwrap(), "https://raw.githubusercontent.com/KYVeda/texts/master/TS-Kramam/TS-7.5/TS%207.5%20Krama%20Paaatm%20Sanskrit.BRH")
-    ];
-
-    scrape(samhita, "samhita/TS")?;
-    scrape(padam, "padam/TS");
-    //scrape(kramam, "kramam/TS");
+    let tasks = load_manifest("sources.toml")?;
+    let patterns = PatternRegistry::from_tasks(&tasks)?;
+
+    let mut results: HashMap<String, HashMap<String, Verse>> = HashMap::new();
+    for task in tasks.iter() {
+        let verses = run_task(task, &patterns)?;
+        results.insert(task.name.clone(), verses);
+    }
+
+    if let (Some(padam), Some(kramam)) = (results.get("padam"), results.get("kramam")) {
+        if !kramam.is_empty() {
+            cross_validate_krama(padam, kramam);
+        }
+    }
+
+    if let Some(padam) = results.get("padam") {
+        if !padam.is_empty() {
+            derive_pathas(padam)?;
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,196 @@
+use crate::transliterate::Scheme;
+use serde::Deserialize;
+
+/// One source of URLs for a task: either a literal URL, optionally
+/// overriding the task's shared capture pattern (samhita's kanda
+/// 01/03 source files are formatted slightly differently from
+/// 02/04-07's and need their own pattern), or a templated range of
+/// stub URLs for recensions like padam/kramam that are one file per
+/// kanda.prasna and otherwise identical.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum UrlSource {
+    Literal {
+        url: String,
+        #[serde(default)]
+        pattern: Option<String>,
+    },
+    Stubs {
+        base_url: String,
+        /// `{base}`, `{kanda}` and `{prasna}` are substituted into
+        /// this template for every (kanda, prasna) pair `stubs`
+        /// expands to.
+        url_template: String,
+        /// One (kanda, number_of_prasnas) pair per kanda; expands to
+        /// a URL for every prasna from 1 through that bound,
+        /// inclusive.
+        stubs: Vec<(i32, i32)>,
+    },
+}
+
+impl UrlSource {
+    /// Expand into `(url, pattern_override)` pairs, in stub order.
+    fn expand(&self) -> Vec<(String, Option<String>)> {
+        match self {
+            UrlSource::Literal { url, pattern } => vec![(url.clone(), pattern.clone())],
+            UrlSource::Stubs {
+                base_url,
+                url_template,
+                stubs,
+            } => stubs
+                .iter()
+                .flat_map(|&(kanda, prasna_count)| {
+                    let url_template = url_template.clone();
+                    let base_url = base_url.clone();
+                    (1..=prasna_count).map(move |prasna| {
+                        let url = url_template
+                            .replace("{base}", &base_url)
+                            .replace("{kanda}", &kanda.to_string())
+                            .replace("{prasna}", &prasna.to_string());
+                        (url, None)
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One named recension to scrape, as declared in `sources.toml`.
+///
+/// A task owns everything needed to turn a set of stub URLs into a
+/// `HashMap<String, Verse>` on disk: the capture pattern, how many
+/// dotted index parts that pattern's first group produces, and where
+/// the resulting JSON should be written.
+#[derive(Debug, Deserialize)]
+pub struct ScrapeTask {
+    /// Name of the task, e.g. "samhita", "padam", "kramam". Used for
+    /// logging and by `main` to find the "padam" and "kramam" results
+    /// for cross-validation; `output` controls the file that gets written.
+    pub name: String,
+    /// Whether this task should run. Lets a text be disabled in the
+    /// manifest instead of commented out of `main`.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Regex with two capture groups: (1) the dotted verse index,
+    /// (2) the verse text. Used for every URL that doesn't name its
+    /// own override in `urls`.
+    pub pattern: String,
+    /// Number of dotted parts `pattern`'s index group is expected to
+    /// produce. `Verse` is hard-wired to a 4-part bhaga.kanda.prasna.panasa
+    /// index, so this must be 4 for now; `load_manifest` rejects any other
+    /// value at load time rather than letting extraction panic later on
+    /// otherwise well-formed data.
+    pub index_arity: usize,
+    /// Literal and/or templated sources of URLs to fetch for this
+    /// task. Use `expand_urls` to resolve these to concrete
+    /// `(url, pattern_override)` pairs.
+    urls: Vec<UrlSource>,
+    /// Output path, relative to `./outputs/`, without extension.
+    pub output: String,
+    /// Bound on concurrently in-flight requests for this task's URLs.
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    /// Encoding the fetched source text is in, so it can be
+    /// transliterated to the pipeline's canonical scheme.
+    #[serde(default = "default_source_scheme")]
+    pub source_scheme: Scheme,
+}
+
+impl ScrapeTask {
+    /// Every URL this task fetches, paired with the capture pattern to
+    /// use for it: the URL's own override if it named one, else
+    /// `self.pattern`.
+    pub fn expand_urls(&self) -> Vec<(String, String)> {
+        self.urls
+            .iter()
+            .flat_map(UrlSource::expand)
+            .map(|(url, pattern_override)| {
+                (
+                    url,
+                    pattern_override.unwrap_or_else(|| self.pattern.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_concurrency() -> usize {
+    8
+}
+
+fn default_source_scheme() -> Scheme {
+    Scheme::Baraha
+}
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    #[serde(rename = "task")]
+    tasks: Vec<ScrapeTask>,
+}
+
+/// Load and parse `sources.toml` into the list of declared tasks.
+///
+/// Tasks with `enabled = false` are still returned so callers can
+/// report what was skipped; `run_task` is expected to check `enabled`.
+pub fn load_manifest(path: &str) -> Result<Vec<ScrapeTask>, Box<dyn std::error::Error>> {
+    let raw = std::fs::read_to_string(path)?;
+    let manifest: Manifest = toml::from_str(&raw)?;
+    for task in &manifest.tasks {
+        if task.index_arity != 4 {
+            return Err(format!(
+                "task '{}' declares index_arity = {}, but Verse is hard-wired to a \
+                 4-part bhaga.kanda.prasna.panasa index; add the new fields to Verse \
+                 before declaring a task with a different arity",
+                task.name, task.index_arity
+            )
+            .into());
+        }
+    }
+    Ok(manifest.tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::Write;
+
+    fn write_manifest(index_arity: i32) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("manifest_test_arity_{index_arity}.toml"));
+        let mut file = fs::File::create(&path).unwrap();
+        write!(
+            file,
+            r#"
+[[task]]
+name = "samhita"
+pattern = "(\\d+) pattern"
+index_arity = {index_arity}
+urls = [
+    {{ url = "https://example.com/a" }},
+]
+output = "x"
+"#
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn rejects_a_task_with_index_arity_other_than_four() {
+        let path = write_manifest(3);
+        let err = super::load_manifest(path.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("index_arity = 3"));
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn accepts_a_task_with_index_arity_four() {
+        let path = write_manifest(4);
+        let tasks = super::load_manifest(path.to_str().unwrap()).unwrap();
+        assert_eq!(tasks.len(), 1);
+        fs::remove_file(&path).ok();
+    }
+}
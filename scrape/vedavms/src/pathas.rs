@@ -0,0 +1,153 @@
+//! Derives the recited pāṭha forms (krama, jaṭā, ghana) from an
+//! ordered sequence of padapāṭha words, and cross-validates the
+//! derived krama against the separately scraped kramam text.
+//!
+//! Sandhi-joined words in the padam text are expected to already be
+//! single tokens by the time they reach these functions (i.e. the
+//! caller has split on whitespace, not on phonetic boundaries).
+
+/// Overlapping word pairs (p1 p2)(p2 p3)...(pn-1 pn). The final pair
+/// is closed as (pn-1 pn pn) by convention, so it gets an extra repeat
+/// of the last word rather than following the normal two-word shape.
+pub fn to_krama(words: &[String]) -> Vec<String> {
+    if words.len() < 2 {
+        return Vec::new();
+    }
+    let last = words.len() - 2;
+    (0..words.len() - 1)
+        .map(|i| {
+            if i == last {
+                format!("{} {} {}", words[i], words[i + 1], words[i + 1])
+            } else {
+                format!("{} {}", words[i], words[i + 1])
+            }
+        })
+        .collect()
+}
+
+/// Expands each adjacent pair A B into "A B B A A B".
+pub fn to_jata(words: &[String]) -> Vec<String> {
+    (0..words.len().saturating_sub(1))
+        .map(|i| {
+            let (a, b) = (&words[i], &words[i + 1]);
+            format!("{a} {b} {b} {a} {a} {b}")
+        })
+        .collect()
+}
+
+/// Expands each three-word window A B C into "A B B A A B C C B A A B C",
+/// advancing by one word between windows.
+pub fn to_ghana(words: &[String]) -> Vec<String> {
+    (0..words.len().saturating_sub(2))
+        .map(|i| {
+            let (a, b, c) = (&words[i], &words[i + 1], &words[i + 2]);
+            format!("{a} {b} {b} {a} {a} {b} {c} {c} {b} {a} {a} {b} {c}")
+        })
+        .collect()
+}
+
+/// Word-level diff between a krama derived with `to_krama` and the
+/// krama text scraped from KYVeda, after normalizing whitespace and
+/// avagraha marks. Reports every mismatched word position so a run
+/// can flag a suspect pāṭha instead of silently diverging from it.
+pub struct KramaComparison {
+    pub total_words: usize,
+    pub mismatches: Vec<(usize, String, String)>,
+}
+
+fn normalize(text: &str) -> String {
+    text.replace(['\'', '\u{093D}'], "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn compare_krama(generated: &[String], scraped: &str) -> KramaComparison {
+    let generated_words: Vec<String> = normalize(&generated.join(" "))
+        .split(' ')
+        .map(|w| w.to_string())
+        .collect();
+    let scraped_words: Vec<String> = normalize(scraped)
+        .split(' ')
+        .map(|w| w.to_string())
+        .collect();
+
+    let total_words = generated_words.len().max(scraped_words.len());
+    let mismatches = (0..total_words)
+        .filter_map(|i| {
+            let g = generated_words.get(i).cloned().unwrap_or_default();
+            let s = scraped_words.get(i).cloned().unwrap_or_default();
+            if g != s {
+                Some((i, g, s))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    KramaComparison {
+        total_words,
+        mismatches,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn to_krama_pairs_words_and_closes_with_a_repeat() {
+        let padam = words("agnim iile purohitam");
+        assert_eq!(
+            to_krama(&padam),
+            vec!["agnim iile", "iile purohitam purohitam"]
+        );
+    }
+
+    #[test]
+    fn to_krama_of_fewer_than_two_words_is_empty() {
+        assert_eq!(to_krama(&words("agnim")), Vec::<String>::new());
+    }
+
+    #[test]
+    fn to_jata_expands_each_adjacent_pair() {
+        let padam = words("agnim iile purohitam");
+        assert_eq!(
+            to_jata(&padam),
+            vec![
+                "agnim iile iile agnim agnim iile",
+                "iile purohitam purohitam iile iile purohitam",
+            ]
+        );
+    }
+
+    #[test]
+    fn to_ghana_expands_each_three_word_window() {
+        let padam = words("agnim iile purohitam yajnasya");
+        assert_eq!(
+            to_ghana(&padam),
+            vec![
+                "agnim iile iile agnim agnim iile purohitam purohitam iile agnim agnim iile purohitam",
+                "iile purohitam purohitam iile iile purohitam yajnasya yajnasya purohitam iile iile purohitam yajnasya",
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_krama_reports_word_mismatches() {
+        // to_krama("agnim iile purohitam") = ["agnim iile", "iile
+        // purohitam purohitam"], i.e. the words "agnim iile iile
+        // purohitam purohitam" once joined.
+        let generated = to_krama(&words("agnim iile purohitam"));
+        let comparison = compare_krama(&generated, "agnim iile iile devam purohitam");
+        assert_eq!(comparison.total_words, 5);
+        assert_eq!(
+            comparison.mismatches,
+            vec![(3, "purohitam".to_string(), "devam".to_string())]
+        );
+    }
+}
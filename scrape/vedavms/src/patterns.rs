@@ -0,0 +1,251 @@
+use crate::manifest::ScrapeTask;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Compiled capture patterns, keyed by the pattern string itself.
+/// Every pattern referenced anywhere in the manifest - a task's own
+/// `pattern` plus any per-URL overrides - is compiled once here, so a
+/// malformed regex fails at startup instead of wherever a task happens
+/// to fetch the URL that needed it.
+pub struct PatternRegistry {
+    patterns: HashMap<String, Regex>,
+}
+
+impl PatternRegistry {
+    pub fn from_tasks(tasks: &[ScrapeTask]) -> Result<Self, regex::Error> {
+        let mut patterns = HashMap::new();
+        for task in tasks {
+            for (_, pattern) in task.expand_urls() {
+                if let std::collections::hash_map::Entry::Vacant(entry) =
+                    patterns.entry(pattern.clone())
+                {
+                    entry.insert(Regex::new(&pattern)?);
+                }
+            }
+        }
+        Ok(Self { patterns })
+    }
+
+    pub fn get(&self, pattern: &str) -> Option<&Regex> {
+        self.patterns.get(pattern)
+    }
+}
+
+/// How many captures of a text a pattern accepted vs. rejected as
+/// malformed, so a scrape can report data quality instead of just
+/// panicking on the first bad line.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub matched: usize,
+    pub rejected: usize,
+}
+
+/// Run `pattern` over `text`, returning the (index parts, verse text)
+/// pairs whose index has exactly `index_arity` dotted parts that all
+/// parse as integers - every `Vec<i32>` handed back is already known
+/// to have that length, so callers never need to re-check it. Non-
+/// conforming matches are logged and skipped rather than unwrapped, so
+/// one malformed source line can't abort a scrape.
+pub fn validate_captures(
+    pattern: &Regex,
+    index_arity: usize,
+    text: &str,
+) -> (Vec<(Vec<i32>, String)>, ValidationReport) {
+    let mut accepted = Vec::new();
+    let mut report = ValidationReport::default();
+
+    for cap in pattern.captures_iter(text) {
+        let (Some(index_match), Some(text_match)) = (cap.get(1), cap.get(2)) else {
+            report.rejected += 1;
+            continue;
+        };
+        let verse_index = index_match.as_str();
+        let parts: Result<Vec<i32>, _> = verse_index.split('.').map(|p| p.parse::<i32>()).collect();
+        match parts {
+            Ok(parts) if parts.len() == index_arity => {
+                report.matched += 1;
+                accepted.push((parts, text_match.as_str().trim().to_string()));
+            }
+            Ok(parts) => {
+                eprintln!(
+                    "skipping '{}': index has {} parts, expected {}",
+                    verse_index,
+                    parts.len(),
+                    index_arity
+                );
+                report.rejected += 1;
+            }
+            Err(_) => {
+                eprintln!("skipping '{}': index is not all integers", verse_index);
+                report.rejected += 1;
+            }
+        }
+    }
+
+    (accepted, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fixtures mirror the shape of the actual KYVeda source files
+    // (sources.toml's per-task patterns), not full real pages.
+    const SAMHITA_PATTERN: &str =
+        r"TS (\d+\.\d+\.\d+\.\d+)\n([\s\S]*?)(?:\nTS \d+\.\d+\.\d+\.\d+|$)";
+    const PADAM_PATTERN: &str = r"(\d+\.\d+\.\d+\.\d+)\s+([^0-9]+)";
+    const KRAMAM_PATTERN: &str = r"T\.S\.(\d+\.\d+\.\d+\.\d+) - kramam\n([^(\n]+)";
+
+    #[test]
+    fn samhita_pattern_captures_index_and_text() {
+        let pattern = Regex::new(SAMHITA_PATTERN).unwrap();
+        let text = "\nTS 1.1.1.1\nfirst verse text\n";
+        let (accepted, report) = validate_captures(&pattern, 4, text);
+        assert_eq!(
+            report,
+            ValidationReport {
+                matched: 1,
+                rejected: 0
+            }
+        );
+        assert_eq!(
+            accepted[0],
+            (vec![1, 1, 1, 1], "first verse text".to_string())
+        );
+    }
+
+    // The non-capturing terminator `(?:\nTS ...|$)` is consumed as part
+    // of each match, not just looked ahead at, so with markers on every
+    // side the next search position starts past the following marker -
+    // this pattern only ever captures odd-numbered verses out of a
+    // back-to-back run. That's the upstream source format's pattern,
+    // unchanged here; this test documents the behavior rather than
+    // papering over it.
+    #[test]
+    fn samhita_pattern_skips_every_other_back_to_back_marker() {
+        let pattern = Regex::new(SAMHITA_PATTERN).unwrap();
+        let text =
+            "\nTS 1.1.1.1\nfirst verse text\nTS 1.1.1.2\nsecond verse text\nTS 1.1.1.3\nthird verse text\n";
+        let (accepted, _report) = validate_captures(&pattern, 4, text);
+        assert_eq!(
+            accepted,
+            vec![
+                (vec![1, 1, 1, 1], "first verse text".to_string()),
+                (vec![1, 1, 1, 3], "third verse text".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn padam_pattern_captures_index_and_text() {
+        let pattern = Regex::new(PADAM_PATTERN).unwrap();
+        // Words must stay digit-free: `[^0-9]+` stops at the first
+        // digit, same as it would on the next verse's leading index.
+        let text = "1.1.1.1 agna aa yaahi\n1.1.1.2 iilenyo asi\n";
+        let (accepted, report) = validate_captures(&pattern, 4, text);
+        assert_eq!(
+            report,
+            ValidationReport {
+                matched: 2,
+                rejected: 0
+            }
+        );
+        assert_eq!(accepted[0], (vec![1, 1, 1, 1], "agna aa yaahi".to_string()));
+        assert_eq!(accepted[1], (vec![1, 1, 1, 2], "iilenyo asi".to_string()));
+    }
+
+    #[test]
+    fn kramam_pattern_captures_index_and_text() {
+        let pattern = Regex::new(KRAMAM_PATTERN).unwrap();
+        let text =
+            "T.S.1.1.1.1 - kramam\nfirst krama text\nT.S.1.1.1.2 - kramam\nsecond krama text\n";
+        let (accepted, report) = validate_captures(&pattern, 4, text);
+        assert_eq!(
+            report,
+            ValidationReport {
+                matched: 2,
+                rejected: 0
+            }
+        );
+        assert_eq!(
+            accepted,
+            vec![
+                (vec![1, 1, 1, 1], "first krama text".to_string()),
+                (vec![1, 1, 1, 2], "second krama text".to_string()),
+            ]
+        );
+    }
+
+    // PADAM_PATTERN's `\d+\.\d+\.\d+\.\d+` bakes the arity straight
+    // into the regex, so a line with a different number of dotted
+    // parts never reaches validate_captures as a candidate match in
+    // the first place. These two tests use a looser index group, like
+    // a hand-maintained source file might produce, to exercise
+    // validate_captures's own arity/integer checks directly.
+    const LOOSE_INDEX_PATTERN: &str = r"([\d.]+)\s+([^0-9]+)";
+
+    #[test]
+    fn wrong_index_arity_is_skipped_not_panicked() {
+        let pattern = Regex::new(LOOSE_INDEX_PATTERN).unwrap();
+        // Only 3 dotted parts instead of the 4 index_arity expects.
+        let text = "1.1.1 malformed index\n1.1.1.2 well-formed index\n";
+        let (accepted, report) = validate_captures(&pattern, 4, text);
+        assert_eq!(
+            report,
+            ValidationReport {
+                matched: 1,
+                rejected: 1
+            }
+        );
+        assert_eq!(accepted[0].0, vec![1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn non_integer_index_part_is_skipped_not_panicked() {
+        let pattern = Regex::new(LOOSE_INDEX_PATTERN).unwrap();
+        // The doubled dot produces an empty dotted part, which fails
+        // to parse as an integer.
+        let text = "1.1.1.. bad index\n1.1.1.2 well-formed index\n";
+        let (accepted, report) = validate_captures(&pattern, 4, text);
+        assert_eq!(
+            report,
+            ValidationReport {
+                matched: 1,
+                rejected: 1
+            }
+        );
+        assert_eq!(accepted[0].0, vec![1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn pattern_registry_resolves_both_task_patterns_and_url_overrides() {
+        use std::fs;
+        use std::io::Write;
+
+        let manifest_path = std::env::temp_dir().join("patterns_test_sources.toml");
+        let mut file = fs::File::create(&manifest_path).unwrap();
+        write!(
+            file,
+            r#"
+[[task]]
+name = "samhita"
+pattern = "default (\\d+) pattern"
+index_arity = 4
+urls = [
+    {{ url = "https://example.com/a", pattern = "override (\\d+) pattern" }},
+    {{ url = "https://example.com/b" }},
+]
+output = "x"
+"#
+        )
+        .unwrap();
+
+        let tasks = crate::manifest::load_manifest(manifest_path.to_str().unwrap()).unwrap();
+        let registry = PatternRegistry::from_tasks(&tasks).unwrap();
+        assert!(registry.get("default (\\d+) pattern").is_some());
+        assert!(registry.get("override (\\d+) pattern").is_some());
+        assert!(registry.get("no such pattern").is_none());
+
+        fs::remove_file(&manifest_path).ok();
+    }
+}
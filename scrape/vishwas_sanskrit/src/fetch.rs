@@ -0,0 +1,74 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
+
+const CACHE_DIR: &str = "./cache";
+const JOURNAL_PATH: &str = "./cache/journal.txt";
+const MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF_MS: u64 = 250;
+
+/// Path the cache would write a fetched page to, keyed by a hash of
+/// its URL so re-runs can find it without re-downloading.
+fn cache_path(url: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    Path::new(CACHE_DIR).join(format!("{:x}.md", hasher.finish()))
+}
+
+/// URLs already marked complete in the journal, so a resumed run only
+/// fetches what's outstanding.
+fn load_journal() -> HashSet<String> {
+    fs::read_to_string(JOURNAL_PATH)
+        .map(|contents| contents.lines().map(|l| l.to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn mark_done(url: &str) -> std::io::Result<()> {
+    fs::create_dir_all(CACHE_DIR)?;
+    let mut journal = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(JOURNAL_PATH)?;
+    use std::io::Write;
+    writeln!(journal, "{}", url)
+}
+
+/// Fetch `url`, serving from the on-disk cache when a prior run already
+/// completed it. Transient failures are retried with exponential
+/// backoff and jitter, up to `MAX_RETRIES` attempts.
+pub fn fetch_cached(url: &str) -> Result<String, Box<dyn std::error::Error>> {
+    fs::create_dir_all(CACHE_DIR)?;
+    let path = cache_path(url);
+    if load_journal().contains(url) {
+        if let Ok(cached) = fs::read_to_string(&path) {
+            return Ok(cached);
+        }
+    }
+
+    let mut attempt = 0;
+    loop {
+        match reqwest::blocking::get(url).and_then(|r| r.error_for_status()) {
+            Ok(response) => {
+                let text = response.text()?;
+                fs::write(&path, &text)?;
+                mark_done(url)?;
+                return Ok(text);
+            }
+            Err(err) if attempt < MAX_RETRIES => {
+                attempt += 1;
+                let jitter = rand::random::<u64>() % BASE_BACKOFF_MS;
+                let backoff = BASE_BACKOFF_MS * 2u64.pow(attempt - 1) + jitter;
+                eprintln!(
+                    "Fetch of {} failed ({}), retrying in {}ms (attempt {}/{})",
+                    url, err, backoff, attempt, MAX_RETRIES
+                );
+                sleep(Duration::from_millis(backoff));
+            }
+            Err(err) => return Err(Box::new(err)),
+        }
+    }
+}
@@ -1,9 +1,13 @@
-use reqwest::blocking::get;
+mod fetch;
+mod transliterate;
+
+use fetch::fetch_cached;
 use serde_json::json;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
 use std::io::Write;
+use transliterate::{transliterate, Scheme};
 
 fn get_samhita_urls() -> Vec<String> {
     let samhita: String = "https://raw.githubusercontent.com/udapaana/raw_etexts/master/vedaH/yajur/taittirIya/mUlam/saMhitA".to_string();
@@ -46,7 +50,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             .unwrap();
 
         // Fetch text from URL
-        let text = get(url)?.text()?;
+        let text = fetch_cached(url)?;
 
         // Parse verses
         let verses = parse_verses(&text);
@@ -61,7 +65,9 @@ fn main() -> Result<(), Box<dyn Error>> {
                     "kanda": kanda,
                     "prasna": prasna,
                     "anuvaka": anuvaka,
-                    "deva": strip_index(verse),
+                    "deva": stripped,
+                    "scheme": Scheme::Devanagari,
+                    "iast": transliterate(&stripped, Scheme::Devanagari, Scheme::Iast),
                 });
                 parsed.insert(format!("{}.{}.{}", kanda, prasna, anuvaka), json_output);
                 anuvaka += 1;
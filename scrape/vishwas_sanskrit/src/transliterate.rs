@@ -0,0 +1,738 @@
+/// Transliteration between the encodings the scrapers see: Baraha
+/// (the KYVeda `.BRH` source files), Devanagari (udapaana's samhita
+/// source, which this crate fetches), and the two common ASCII romanizations, IAST and
+/// SLP1, plus Harvard-Kyoto for interop with other tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scheme {
+    Baraha,
+    Devanagari,
+    Iast,
+    Slp1,
+    HarvardKyoto,
+}
+
+/// One akshara's spelling in each scheme, longest-Baraha-token first
+/// within a given length class so greedy matching prefers digraphs
+/// ("kh") over their prefix ("k").
+struct Akshara {
+    baraha: &'static str,
+    deva: &'static str,
+    iast: &'static str,
+    slp1: &'static str,
+    hk: &'static str,
+    /// Whether this akshara is a consonant letter that, in Devanagari,
+    /// carries an inherent "a" unless followed by a mātrā or virāma.
+    is_consonant: bool,
+}
+
+const AKSHARAS: &[Akshara] = &[
+    // vowels
+    Akshara {
+        baraha: "aa",
+        deva: "आ",
+        iast: "ā",
+        slp1: "A",
+        hk: "A",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "ii",
+        deva: "ई",
+        iast: "ī",
+        slp1: "I",
+        hk: "I",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "uu",
+        deva: "ऊ",
+        iast: "ū",
+        slp1: "U",
+        hk: "U",
+        is_consonant: false,
+    },
+    // ṝ before ṛ: Harvard-Kyoto spells them "RR" and "R".
+    Akshara {
+        baraha: "RRI",
+        deva: "ॠ",
+        iast: "ṝ",
+        slp1: "F",
+        hk: "RR",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "RRi",
+        deva: "ऋ",
+        iast: "ṛ",
+        slp1: "f",
+        hk: "R",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "ai",
+        deva: "ऐ",
+        iast: "ai",
+        slp1: "E",
+        hk: "ai",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "au",
+        deva: "औ",
+        iast: "au",
+        slp1: "O",
+        hk: "au",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "a",
+        deva: "अ",
+        iast: "a",
+        slp1: "a",
+        hk: "a",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "i",
+        deva: "इ",
+        iast: "i",
+        slp1: "i",
+        hk: "i",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "u",
+        deva: "उ",
+        iast: "u",
+        slp1: "u",
+        hk: "u",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "e",
+        deva: "ए",
+        iast: "e",
+        slp1: "e",
+        hk: "e",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "o",
+        deva: "ओ",
+        iast: "o",
+        slp1: "o",
+        hk: "o",
+        is_consonant: false,
+    },
+    // velars
+    Akshara {
+        baraha: "kh",
+        deva: "ख",
+        iast: "kh",
+        slp1: "K",
+        hk: "kh",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "gh",
+        deva: "घ",
+        iast: "gh",
+        slp1: "G",
+        hk: "gh",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "~N",
+        deva: "ङ",
+        iast: "ṅ",
+        slp1: "N",
+        hk: "G",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "k",
+        deva: "क",
+        iast: "k",
+        slp1: "k",
+        hk: "k",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "g",
+        deva: "ग",
+        iast: "g",
+        slp1: "g",
+        hk: "g",
+        is_consonant: true,
+    },
+    // palatals
+    Akshara {
+        baraha: "Ch",
+        deva: "छ",
+        iast: "ch",
+        slp1: "C",
+        hk: "ch",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "jh",
+        deva: "झ",
+        iast: "jh",
+        slp1: "J",
+        hk: "jh",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "~n",
+        deva: "ञ",
+        iast: "ñ",
+        slp1: "Y",
+        hk: "J",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "ch",
+        deva: "च",
+        iast: "c",
+        slp1: "c",
+        hk: "c",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "j",
+        deva: "ज",
+        iast: "j",
+        slp1: "j",
+        hk: "j",
+        is_consonant: true,
+    },
+    // retroflexes
+    Akshara {
+        baraha: "Th",
+        deva: "ठ",
+        iast: "ṭh",
+        slp1: "W",
+        hk: "Th",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "Dh",
+        deva: "ढ",
+        iast: "ḍh",
+        slp1: "Q",
+        hk: "Dh",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "T",
+        deva: "ट",
+        iast: "ṭ",
+        slp1: "w",
+        hk: "T",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "D",
+        deva: "ड",
+        iast: "ḍ",
+        slp1: "q",
+        hk: "D",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "N",
+        deva: "ण",
+        iast: "ṇ",
+        slp1: "R",
+        hk: "N",
+        is_consonant: true,
+    },
+    // dentals
+    Akshara {
+        baraha: "th",
+        deva: "थ",
+        iast: "th",
+        slp1: "T",
+        hk: "th",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "dh",
+        deva: "ध",
+        iast: "dh",
+        slp1: "D",
+        hk: "dh",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "t",
+        deva: "त",
+        iast: "t",
+        slp1: "t",
+        hk: "t",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "d",
+        deva: "द",
+        iast: "d",
+        slp1: "d",
+        hk: "d",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "n",
+        deva: "न",
+        iast: "n",
+        slp1: "n",
+        hk: "n",
+        is_consonant: true,
+    },
+    // labials
+    Akshara {
+        baraha: "ph",
+        deva: "फ",
+        iast: "ph",
+        slp1: "P",
+        hk: "ph",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "bh",
+        deva: "भ",
+        iast: "bh",
+        slp1: "B",
+        hk: "bh",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "p",
+        deva: "प",
+        iast: "p",
+        slp1: "p",
+        hk: "p",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "b",
+        deva: "ब",
+        iast: "b",
+        slp1: "b",
+        hk: "b",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "m",
+        deva: "म",
+        iast: "m",
+        slp1: "m",
+        hk: "m",
+        is_consonant: true,
+    },
+    // semivowels, sibilants, h
+    Akshara {
+        baraha: "y",
+        deva: "य",
+        iast: "y",
+        slp1: "y",
+        hk: "y",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "r",
+        deva: "र",
+        iast: "r",
+        slp1: "r",
+        hk: "r",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "l",
+        deva: "ल",
+        iast: "l",
+        slp1: "l",
+        hk: "l",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "v",
+        deva: "व",
+        iast: "v",
+        slp1: "v",
+        hk: "v",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "sh",
+        deva: "श",
+        iast: "ś",
+        slp1: "S",
+        hk: "z",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "Sh",
+        deva: "ष",
+        iast: "ṣ",
+        slp1: "z",
+        hk: "S",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "s",
+        deva: "स",
+        iast: "s",
+        slp1: "s",
+        hk: "s",
+        is_consonant: true,
+    },
+    Akshara {
+        baraha: "h",
+        deva: "ह",
+        iast: "h",
+        slp1: "h",
+        hk: "h",
+        is_consonant: true,
+    },
+    // anusvara, visarga
+    Akshara {
+        baraha: "M",
+        deva: "ं",
+        iast: "ṃ",
+        slp1: "M",
+        hk: "M",
+        is_consonant: false,
+    },
+    Akshara {
+        baraha: "H",
+        deva: "ः",
+        iast: "ḥ",
+        slp1: "H",
+        hk: "H",
+        is_consonant: false,
+    },
+];
+
+/// Vedic svara marks. This crate's own convention (there is no single
+/// universal ASCII standard for these): anudātta as a prefixed low
+/// mark, svarita as a suffixed raised mark, udātta left unmarked.
+struct Svara {
+    baraha: &'static str,
+    deva: &'static str,
+    iast: &'static str,
+    slp1: &'static str,
+    hk: &'static str,
+}
+
+const SVARAS: &[Svara] = &[
+    // anudātta: Devanagari U+0952 / "_" in Baraha / "\" in the romanizations
+    Svara {
+        baraha: "_",
+        deva: "॒",
+        iast: "\\",
+        slp1: "\\",
+        hk: "\\",
+    },
+    // svarita: Devanagari U+0951 / "#" in Baraha / "^" in the romanizations
+    Svara {
+        baraha: "#",
+        deva: "॑",
+        iast: "^",
+        slp1: "^",
+        hk: "^",
+    },
+];
+
+fn token(a: &Akshara, scheme: Scheme) -> &'static str {
+    match scheme {
+        Scheme::Baraha => a.baraha,
+        Scheme::Devanagari => a.deva,
+        Scheme::Iast => a.iast,
+        Scheme::Slp1 => a.slp1,
+        Scheme::HarvardKyoto => a.hk,
+    }
+}
+
+fn svara_token(s: &Svara, scheme: Scheme) -> &'static str {
+    match scheme {
+        Scheme::Baraha => s.baraha,
+        Scheme::Devanagari => s.deva,
+        Scheme::Iast => s.iast,
+        Scheme::Slp1 => s.slp1,
+        Scheme::HarvardKyoto => s.hk,
+    }
+}
+
+/// Re-encode `text` from `from` to `to` by greedy longest-token
+/// substitution, mapping both base aksharas and svara marks.
+/// Unrecognized characters (punctuation, digits, whitespace) pass
+/// through unchanged.
+///
+/// Only correct between two phonemic schemes (every akshara spelled out
+/// in full, one after another). Devanagari is an abugida instead - a
+/// consonant carries an inherent "a" unless followed by a dependent
+/// vowel sign or virāma - so callers must go through
+/// `devanagari_to_scheme`/`scheme_to_devanagari` whenever Devanagari is
+/// one of the two schemes; `transliterate` below does that routing.
+fn transliterate_tokenwise(text: &str, from: Scheme, to: Scheme) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    'outer: while !rest.is_empty() {
+        for svara in SVARAS {
+            let needle = svara_token(svara, from);
+            if !needle.is_empty() && rest.starts_with(needle) {
+                out.push_str(svara_token(svara, to));
+                rest = &rest[needle.len()..];
+                continue 'outer;
+            }
+        }
+        for akshara in AKSHARAS {
+            let needle = token(akshara, from);
+            if !needle.is_empty() && rest.starts_with(needle) {
+                out.push_str(token(akshara, to));
+                rest = &rest[needle.len()..];
+                continue 'outer;
+            }
+        }
+        // No mapping for this character; copy it through as-is.
+        let next_char_len = rest.chars().next().map(char::len_utf8).unwrap_or(1);
+        out.push_str(&rest[..next_char_len]);
+        rest = &rest[next_char_len..];
+    }
+    out
+}
+
+/// Devanagari's virāma (halant, U+094D): attached to a consonant, it
+/// suppresses that consonant's inherent "a" instead of a dependent
+/// vowel sign following it.
+const VIRAMA_DEVA: char = '\u{094D}';
+
+/// Maps a Devanagari dependent vowel sign (mātrā) to the Baraha key of
+/// the corresponding independent vowel in `AKSHARAS`, so either
+/// direction can look up that vowel's spelling in any scheme without
+/// duplicating the vowel table.
+struct Matra {
+    deva: char,
+    vowel_baraha: &'static str,
+}
+
+const MATRAS: &[Matra] = &[
+    Matra {
+        deva: 'ा',
+        vowel_baraha: "aa",
+    },
+    Matra {
+        deva: 'ि',
+        vowel_baraha: "i",
+    },
+    Matra {
+        deva: 'ी',
+        vowel_baraha: "ii",
+    },
+    Matra {
+        deva: 'ु',
+        vowel_baraha: "u",
+    },
+    Matra {
+        deva: 'ू',
+        vowel_baraha: "uu",
+    },
+    Matra {
+        deva: 'ृ',
+        vowel_baraha: "RRi",
+    },
+    Matra {
+        deva: 'ॄ',
+        vowel_baraha: "RRI",
+    },
+    Matra {
+        deva: 'े',
+        vowel_baraha: "e",
+    },
+    Matra {
+        deva: 'ै',
+        vowel_baraha: "ai",
+    },
+    Matra {
+        deva: 'ो',
+        vowel_baraha: "o",
+    },
+    Matra {
+        deva: 'ौ',
+        vowel_baraha: "au",
+    },
+];
+
+fn akshara_by_baraha(baraha: &str) -> &'static Akshara {
+    AKSHARAS
+        .iter()
+        .find(|a| a.baraha == baraha)
+        .expect("every MATRAS entry's vowel_baraha names a real AKSHARAS entry")
+}
+
+/// Decode Devanagari into `to`, honoring the abugida rules a plain
+/// token substitution can't: a consonant letter carries an inherent
+/// "a" unless the next character is a dependent vowel sign (which
+/// replaces it) or a virāma (which drops it, for consonant conjuncts).
+fn devanagari_to_scheme(text: &str, to: Scheme) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if let Some(a) = AKSHARAS.iter().find(|a| a.deva.chars().eq([c])) {
+            out.push_str(token(a, to));
+            if a.is_consonant {
+                match chars.get(i + 1) {
+                    Some(&VIRAMA_DEVA) => {
+                        i += 2;
+                        continue;
+                    }
+                    Some(next) => {
+                        if let Some(m) = MATRAS.iter().find(|m| m.deva == *next) {
+                            out.push_str(token(akshara_by_baraha(m.vowel_baraha), to));
+                            i += 2;
+                            continue;
+                        }
+                        out.push_str(token(akshara_by_baraha("a"), to));
+                    }
+                    None => out.push_str(token(akshara_by_baraha("a"), to)),
+                }
+            }
+            i += 1;
+            continue;
+        }
+        if let Some(s) = SVARAS.iter().find(|s| s.deva.chars().eq([c])) {
+            out.push_str(svara_token(s, to));
+            i += 1;
+            continue;
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// Encode `from`-scheme text into Devanagari: each consonant is held
+/// back (`pending`) until we know what follows it, so it can be
+/// written with the right dependent vowel sign, joined to the next
+/// consonant with a virāma (conjuncts), or left to its inherent "a".
+fn scheme_to_devanagari(text: &str, from: Scheme) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    let mut pending: Option<&Akshara> = None;
+    'outer: while !rest.is_empty() {
+        for svara in SVARAS {
+            let needle = svara_token(svara, from);
+            if !needle.is_empty() && rest.starts_with(needle) {
+                if let Some(pc) = pending.take() {
+                    out.push_str(pc.deva);
+                }
+                out.push_str(svara.deva);
+                rest = &rest[needle.len()..];
+                continue 'outer;
+            }
+        }
+        for akshara in AKSHARAS {
+            let needle = token(akshara, from);
+            if !needle.is_empty() && rest.starts_with(needle) {
+                if akshara.is_consonant {
+                    if let Some(pc) = pending.take() {
+                        out.push_str(pc.deva);
+                        out.push(VIRAMA_DEVA);
+                    }
+                    pending = Some(akshara);
+                } else if let Some(pc) = pending.take() {
+                    out.push_str(pc.deva);
+                    if akshara.baraha != "a" {
+                        match MATRAS.iter().find(|m| m.vowel_baraha == akshara.baraha) {
+                            Some(m) => out.push(m.deva),
+                            None => out.push_str(akshara.deva),
+                        }
+                    }
+                } else {
+                    out.push_str(akshara.deva);
+                }
+                rest = &rest[needle.len()..];
+                continue 'outer;
+            }
+        }
+        if let Some(pc) = pending.take() {
+            out.push_str(pc.deva);
+        }
+        let next_char_len = rest.chars().next().map(char::len_utf8).unwrap_or(1);
+        out.push_str(&rest[..next_char_len]);
+        rest = &rest[next_char_len..];
+    }
+    if let Some(pc) = pending.take() {
+        out.push_str(pc.deva);
+    }
+    out
+}
+
+/// Re-encode `text` from `from` to `to`. Routes through
+/// `devanagari_to_scheme`/`scheme_to_devanagari` whenever Devanagari is
+/// involved, since its abugida structure needs mātrā/virāma-aware
+/// handling that plain token substitution can't provide; otherwise
+/// falls back to `transliterate_tokenwise` for the phonemic schemes.
+pub fn transliterate(text: &str, from: Scheme, to: Scheme) -> String {
+    if from == to {
+        return text.to_string();
+    }
+    match (from, to) {
+        (Scheme::Devanagari, _) => devanagari_to_scheme(text, to),
+        (_, Scheme::Devanagari) => scheme_to_devanagari(text, from),
+        _ => transliterate_tokenwise(text, from, to),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn devanagari_conjunct_to_iast() {
+        assert_eq!(
+            transliterate("राम", Scheme::Devanagari, Scheme::Iast),
+            "rāma"
+        );
+        assert_eq!(
+            transliterate("नमः", Scheme::Devanagari, Scheme::Iast),
+            "namaḥ"
+        );
+        assert_eq!(
+            transliterate("धर्म", Scheme::Devanagari, Scheme::Iast),
+            "dharma"
+        );
+    }
+
+    #[test]
+    fn iast_to_devanagari_conjunct() {
+        assert_eq!(
+            transliterate("rāma", Scheme::Iast, Scheme::Devanagari),
+            "राम"
+        );
+        assert_eq!(
+            transliterate("namaḥ", Scheme::Iast, Scheme::Devanagari),
+            "नमः"
+        );
+        assert_eq!(
+            transliterate("dharma", Scheme::Iast, Scheme::Devanagari),
+            "धर्म"
+        );
+    }
+
+    #[test]
+    fn devanagari_roundtrips_through_iast() {
+        for word in ["राम", "नमः", "धर्म", "कृष्ण"] {
+            let iast = transliterate(word, Scheme::Devanagari, Scheme::Iast);
+            assert_eq!(transliterate(&iast, Scheme::Iast, Scheme::Devanagari), word);
+        }
+    }
+
+    #[test]
+    fn phonemic_schemes_still_token_substitute() {
+        assert_eq!(transliterate("rAma", Scheme::Slp1, Scheme::Iast), "rāma");
+    }
+}